@@ -1,36 +1,146 @@
 use std::f32::consts::PI;
 
+use bevy::ecs::system::SystemParam;
 use bevy::math::Vec3Swizzles;
 use bevy::pbr::{NotShadowCaster, NotShadowReceiver};
 use bevy::prelude::*;
-use rusalka::NoiseGenerator;
+use bevy::utils::{HashMap, HashSet};
 
 fn main() {
-    App::new()
-        .add_plugins(DefaultPlugins)
+    let mut app = App::new();
+    app.add_plugins(DefaultPlugins)
+        .add_state::<GameState>() // 游戏状态机：Welcome / InGame / Paused
         .insert_resource(TankConfig {
             // 插入 TankConfig 资源
             tank_count: 20,        // 坦克数量
             safe_zone_radius: 8.0, // 安全区域半径
+            use_missiles: true,    // 发射追踪导弹
         })
+        .insert_resource(Gravity(Vec3::new(0.0, -9.82, 0.0))) // 重力，提升为资源以便在 inspector 中实时调整
+        .insert_resource(TurretConfig {
+            rotation_speed: PI, // 转台每秒旋转弧度
+            muzzle_velocity: 20.0, // 出膛速度
+        })
+        .register_type::<TankConfig>() // 注册为可反射类型，供 world inspector 编辑
+        .register_type::<Gravity>()
+        .register_type::<TurretConfig>()
         .init_resource::<CannonBallMesh>() // 初始化 CannonBallMesh 资源
+        .init_resource::<FormationMaker>() // 初始化编队生成器
+        .init_resource::<Score>() // 初始化记分板
+        .init_resource::<AudioAssets>() // 加载音效资源
+        .init_resource::<AudioThrottle>() // 音效播放节流
         .add_startup_systems((setup, tank_spawn)) // 仅仅启动时调用一次
-        .add_systems((
-            // 每帧调用
-            tank_move, // 坦克移动
-            cannon_ball_velocity, // 根据炮弹速度与重力更新自身位置
-            check_safe_zone, // 检测安全区域
-            turret_rotate, // 坦克转台旋转
-            turret_shoot.after(turret_rotate), // 坦克转台发射，在 turret_rotate 之后运行
-        ))
-        .run();
+        .add_system(welcome_prompt_spawn.in_schedule(OnEnter(GameState::Welcome))) // 进入 Welcome 时生成提示
+        .add_system(welcome_prompt_despawn.in_schedule(OnExit(GameState::Welcome))) // 离开 Welcome 时移除提示
+        .add_system(welcome_start.in_set(OnUpdate(GameState::Welcome))) // 按键从 Welcome 进入 InGame
+        .add_system(pause_toggle.run_if(not(in_state(GameState::Welcome)))) // P 暂停 / S 恢复
+        .add_systems(
+            (
+                // 每帧调用，仅在 InGame 时运行
+                tank_move, // 坦克移动
+                cannon_ball_velocity, // 根据炮弹速度与重力更新自身位置
+                missile_velocity, // 导弹朝目标转向并前进
+                lifetime_despawn, // 超过生命周期的实体自动销毁
+                hit_detection, // 检测炮弹/导弹与坦克的碰撞并记分
+                score_display, // 更新记分板 UI 文本
+                check_safe_zone, // 检测安全区域
+                turret_rotate, // 坦克转台旋转
+                turret_shoot.after(turret_rotate), // 坦克转台发射，在 turret_rotate 之后运行
+            )
+                .in_set(OnUpdate(GameState::InGame)),
+        );
+
+    // world inspector 仅在启用 "inspector" feature 时编译进去，不影响 release 构建
+    #[cfg(feature = "inspector")]
+    app.add_plugin(bevy_inspector_egui::quick::WorldInspectorPlugin::new());
+
+    app.run();
+}
+
+// 游戏状态
+#[derive(States, Default, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum GameState {
+    #[default]
+    Welcome, // 欢迎界面，等待玩家开始
+    InGame, // 游戏进行中
+    Paused, // 暂停
+}
+
+// Welcome 界面的提示文本
+#[derive(Component)]
+pub struct WelcomePrompt;
+
+// 生成 Welcome 提示
+fn welcome_prompt_spawn(mut commands: Commands) {
+    commands.spawn((
+        WelcomePrompt,
+        TextBundle::from_section(
+            "Press any key to start",
+            TextStyle {
+                font_size: 40.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            position: UiRect {
+                top: Val::Px(40.0),
+                left: Val::Px(40.0),
+                ..default()
+            },
+            ..default()
+        }),
+    ));
+}
+
+// 移除 Welcome 提示
+fn welcome_prompt_despawn(mut commands: Commands, prompts: Query<Entity, With<WelcomePrompt>>) {
+    for entity in prompts.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+// 按任意键从 Welcome 进入 InGame
+fn welcome_start(keyboard: Res<Input<KeyCode>>, mut next_state: ResMut<NextState<GameState>>) {
+    if keyboard.get_just_pressed().next().is_some() {
+        next_state.set(GameState::InGame);
+    }
+}
+
+// P 暂停，S 恢复
+fn pause_toggle(
+    keyboard: Res<Input<KeyCode>>,
+    state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if keyboard.just_pressed(KeyCode::P) && state.0 == GameState::InGame {
+        next_state.set(GameState::Paused);
+    } else if keyboard.just_pressed(KeyCode::S) && state.0 == GameState::Paused {
+        next_state.set(GameState::InGame);
+    }
 }
 
 // 配置
-#[derive(Resource)]
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
 pub struct TankConfig {
     tank_count: u32,
     safe_zone_radius: f32,
+    use_missiles: bool, // 为 true 时 turret_shoot 发射追踪导弹而非弹道炮弹
+}
+
+// 重力，提升为资源以便通过 world inspector 实时调整
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+pub struct Gravity(Vec3);
+
+// 转台的旋转速度与出膛速度，可通过 world inspector 实时调整
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+pub struct TurretConfig {
+    rotation_speed: f32,
+    muzzle_velocity: f32,
 }
 
 // 坦克
@@ -133,6 +243,28 @@ fn setup(
         NotShadowCaster,   // 不投射阴影
         NotShadowReceiver, // 不接收阴影
     ));
+
+    // 记分板 UI 文本
+    commands.spawn((
+        ScoreText,
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 30.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            position: UiRect {
+                top: Val::Px(10.0),
+                right: Val::Px(10.0),
+                ..default()
+            },
+            ..default()
+        }),
+    ));
 }
 
 // 坦克生成
@@ -141,6 +273,7 @@ fn tank_spawn(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    mut formation_maker: ResMut<FormationMaker>,
 ) {
     let tank_mesh = meshes.add(shape::Cube::new(1.0).into());
     let turret_mesh = meshes.add(
@@ -197,13 +330,23 @@ fn tank_spawn(
             ))
             .add_child(cannon)
             .id();
+
+        // 为这辆坦克分配一个编队，必要时生成新的编队模板
+        let formation = formation_maker.next();
+        let initial_pos = formation.pivot
+            + Vec2::new(
+                formation.radius.x * formation.angle.cos(),
+                formation.radius.y * formation.angle.sin(),
+            );
+
         commands
             .spawn((
                 Tank,
+                formation,
                 PbrBundle {
                     mesh: tank_mesh.clone(),
                     material: material.clone(),
-                    transform: Transform::from_xyz(0.0, 0.5, 0.0),
+                    transform: Transform::from_xyz(initial_pos.x, 0.5, initial_pos.y),
                     ..default()
                 },
             ))
@@ -211,26 +354,93 @@ fn tank_spawn(
     }
 }
 
-// 坦克在地面随机移动与旋转
-fn tank_move(mut tanks: Query<(Entity, &mut Transform), With<Tank>> /*查询 Tank 的 Entity 与 Transform 组件*/, time: Res<Time>) {
+// 编队：描述一辆坦克沿椭圆轨迹绕 pivot 运动的参数
+#[derive(Component)]
+pub struct Formation {
+    radius: Vec2, // 椭圆半径
+    pivot: Vec2,  // 椭圆中心
+    speed: f32,   // 角速度（弧度/秒）
+    angle: f32,   // 当前角度
+}
+
+// 每个编队模板最多容纳的坦克数量，超出后生成新模板
+const TANKS_PER_FORMATION: u32 = 4;
+
+// 编队模板，由 FormationMaker 负责生成与分配
+struct FormationTemplate {
+    pivot: Vec2,
+    radius: Vec2,
+    speed: f32,
+}
+
+// 编队生成器：按模板把坦克分配到共享的椭圆路径上
+#[derive(Resource, Default)]
+pub struct FormationMaker {
+    current: Option<FormationTemplate>,
+    count: u32,
+}
+
+impl FormationMaker {
+    // 为一辆新坦克分配编队，当前模板已满时创建新模板
+    fn next(&mut self) -> Formation {
+        if self.current.is_none() || self.count >= TANKS_PER_FORMATION {
+            self.current = Some(FormationTemplate {
+                pivot: Vec2::new(
+                    (rand::random::<f32>() - 0.5) * 80.0,
+                    (rand::random::<f32>() - 0.5) * 80.0,
+                ),
+                radius: Vec2::new(
+                    5.0 + rand::random::<f32>() * 15.0,
+                    5.0 + rand::random::<f32>() * 15.0,
+                ),
+                speed: 0.2 + rand::random::<f32>() * 0.3,
+            });
+            self.count = 0;
+        }
+        self.count += 1;
+        let template = self.current.as_ref().unwrap();
+        Formation {
+            radius: template.radius,
+            pivot: template.pivot,
+            speed: template.speed,
+            angle: rand::random::<f32>() * 2.0 * PI,
+        }
+    }
+}
+
+// 坦克最大移动速度
+const MAX_TANK_SPEED: f32 = 5.0;
+
+// 坦克沿所属编队的椭圆轨迹移动，并朝向移动方向
+fn tank_move(mut tanks: Query<(&mut Formation, &mut Transform), With<Tank>>, time: Res<Time>) {
     let dt = time.delta_seconds();
-    let generator = NoiseGenerator::new("Nose");
-    for (entity, mut transform) in tanks.iter_mut() {
-        let mut pos = transform.translation;
-        pos.y = entity.index() as f32;
-        pos /= 10.0;
-        // 设置随机的角度与位置
-        let angle: f32 = (0.5 + generator.get(pos.x, pos.y, pos.z)) * 4.0 * PI;
-        let (x, z) = angle.sin_cos();
-        transform.rotation = Quat::from_rotation_y(angle);
-        transform.translation += Vec3::new(x, 0.0, z) * dt * 5.0;
+    for (mut formation, mut transform) in tanks.iter_mut() {
+        // 沿椭圆轨迹推进角度，得到新的目标位置
+        formation.angle += formation.speed * dt;
+        let target = formation.pivot
+            + Vec2::new(
+                formation.radius.x * formation.angle.cos(),
+                formation.radius.y * formation.angle.sin(),
+            );
+        let target = Vec3::new(target.x, transform.translation.y, target.y); // y 保持不变
+
+        // 以限定速度朝目标位置移动，并朝向速度方向
+        let to_target = target - transform.translation;
+        let step = (MAX_TANK_SPEED * dt).min(to_target.length());
+        if let Some(direction) = to_target.try_normalize() {
+            transform.translation += direction * step;
+            transform.rotation = Quat::from_rotation_y(direction.x.atan2(direction.z));
+        }
     }
 }
 
 // 坦克转台旋转
-fn turret_rotate(mut turret: Query<&mut Transform, With<Turret>>, time: Res<Time>) {
-    // 每秒旋转 180 度
-    let rotation_y = Quat::from_rotation_y(time.delta_seconds() * PI);
+fn turret_rotate(
+    mut turret: Query<&mut Transform, With<Turret>>,
+    turret_config: Res<TurretConfig>,
+    time: Res<Time>,
+) {
+    let rotation_y = Quat::from_rotation_y(time.delta_seconds() * turret_config.rotation_speed);
 
     for mut transform in turret.iter_mut() {
         transform.rotation = rotation_y * transform.rotation;
@@ -238,20 +448,64 @@ fn turret_rotate(mut turret: Query<&mut Transform, With<Turret>>, time: Res<Time
 }
 
 // 坦克转台发射
+// 炮弹/导弹两种分支 + 发射音效都离不开这些参数，打包音效后仍有 8 个，故保留该 allow
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::type_complexity)] // Query 元组是 Turret 发射所需字段的直接表达，拆分反而更难读
 fn turret_shoot(
     mut commands: Commands,
     cannon_ball_mesh: Res<CannonBallMesh>,
-    turrets: Query<(&Turret, &Handle<StandardMaterial>, &GlobalTransform), With<Shooting>>,// 查询包含Shooting组件的实体的 Turret、材质、全局变换数据
+    tank_config: Res<TankConfig>,
+    turret_config: Res<TurretConfig>,
+    turrets: Query<
+        (&Turret, &Handle<StandardMaterial>, &GlobalTransform, Option<&Parent>),
+        With<Shooting>,
+    >, // 查询包含Shooting组件的实体的 Turret、材质、全局变换、所属坦克数据
     global_transform_query: Query<&GlobalTransform>,
+    tanks: Query<(Entity, &GlobalTransform), With<Tank>>,
+    mut audio: AudioSink,
 ) {
-    for (turret, material, global_transform) in turrets.iter() {
+    for (turret, material, global_transform, parent) in turrets.iter() {
         let spawn_point_pos = global_transform_query
             .get(turret.spawn_point)
             .unwrap()
             .translation();
+
+        // 配置为发射导弹时，锁定离炮口最近的（非自身所属）坦克
+        if tank_config.use_missiles {
+            let owner = parent.map(Parent::get);
+            let target = tanks
+                .iter()
+                .filter(|(entity, _)| Some(*entity) != owner)
+                .min_by(|(_, a), (_, b)| {
+                    a.translation()
+                        .distance_squared(spawn_point_pos)
+                        .total_cmp(&b.translation().distance_squared(spawn_point_pos))
+                })
+                .map(|(entity, _)| entity);
+
+            if let Some(target) = target {
+                commands.spawn((
+                    Missile {
+                        velocity: global_transform.up() * turret_config.muzzle_velocity,
+                        max_turn: MISSILE_MAX_TURN,
+                        target,
+                    },
+                    Lifetime(Timer::from_seconds(MISSILE_LIFETIME, TimerMode::Once)),
+                    PbrBundle {
+                        material: material.clone(),
+                        transform: Transform::from_translation(spawn_point_pos),
+                        mesh: cannon_ball_mesh.0.clone(),
+                        ..default()
+                    },
+                ));
+                audio.play_fire();
+                continue;
+            }
+        }
+
         commands.spawn((
             CannonBall {
-                velocity: global_transform.up() * 20.0,
+                velocity: global_transform.up() * turret_config.muzzle_velocity,
             },
             PbrBundle {
                 material: material.clone(),
@@ -260,18 +514,86 @@ fn turret_shoot(
                 ..default()
             },
         ));
+        audio.play_fire();
     }
 }
-// 重力
-const GRAVITY: Vec3 = Vec3::new(0.0, -9.82, 0.0);
 
+// 导弹：追踪 target，每帧将速度方向向它钳制转向
+#[derive(Component)]
+pub struct Missile {
+    velocity: Vec3,
+    max_turn: f32, // 每秒最大转向弧度
+    target: Entity,
+}
+
+// 实体剩余生命周期，计时结束后销毁
+#[derive(Component)]
+pub struct Lifetime(Timer);
+
+// 导弹每秒最大转向弧度
+const MISSILE_MAX_TURN: f32 = PI;
+// 导弹存活时间（秒），超时未命中则自毁
+const MISSILE_LIFETIME: f32 = 10.0;
+
+// 导弹朝目标转向并前进，速度大小保持不变
+fn missile_velocity(
+    mut missiles: Query<(&mut Missile, &mut Transform, Entity)>,
+    targets: Query<&GlobalTransform>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    let dt = time.delta_seconds();
+
+    for (mut missile, mut transform, entity) in missiles.iter_mut() {
+        // 目标已不存在（例如被命中销毁）时导弹自毁
+        let target_transform = match targets.get(missile.target) {
+            Ok(target_transform) => target_transform,
+            Err(_) => {
+                commands.entity(entity).despawn();
+                continue;
+            }
+        };
+
+        let to_target = target_transform.translation() - transform.translation;
+
+        // 将当前速度方向朝目标方向钳制转向，钳制角度不超过 max_turn * dt
+        if let Some(desired) = to_target.try_normalize() {
+            let speed = missile.velocity.length();
+            let current = missile.velocity / speed;
+            let angle = current.angle_between(desired);
+            if let Some(axis) = current.cross(desired).try_normalize() {
+                let rotation = Quat::from_axis_angle(axis, angle.min(missile.max_turn * dt));
+                missile.velocity = rotation * current * speed;
+            }
+        }
+
+        transform.translation += missile.velocity * dt;
+        transform.rotation = Quat::from_rotation_arc(Vec3::Y, missile.velocity.normalize());
+    }
+}
+
+// 递减 Lifetime 计时器，到期后销毁实体
+fn lifetime_despawn(
+    mut query: Query<(Entity, &mut Lifetime)>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    for (entity, mut lifetime) in query.iter_mut() {
+        lifetime.0.tick(time.delta());
+        if lifetime.0.finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
 const INVERT_Y: Vec3 = Vec3::new(1.0, -1.0, 1.0);
 
 // 根据炮弹速度与重力更新自身位置
 fn cannon_ball_velocity(
     mut cannon_balls: Query<(&mut CannonBall, &mut Transform, Entity)>,
+    gravity: Res<Gravity>,
     time: Res<Time>,
     mut commands: Commands,
+    mut audio: AudioSink,
 ) {
     let dt = time.delta_seconds();
 
@@ -283,10 +605,12 @@ fn cannon_ball_velocity(
         if transform.translation.y < 0.0 {
             transform.translation *= INVERT_Y;
             cannon_ball.velocity *= INVERT_Y * 0.8;
+
+            audio.play_bounce();
         }
 
         // 重力加速度影响炮弹速度
-        cannon_ball.velocity += GRAVITY * dt;
+        cannon_ball.velocity += gravity.0 * dt;
 
         // 炮弹速度小于 0.1 时 摧毁
         if cannon_ball.velocity.length_squared() < 0.1 {
@@ -295,6 +619,85 @@ fn cannon_ball_velocity(
     }
 }
 
+// 记分板：按发射方的材质（颜色）统计命中数
+#[derive(Resource, Default)]
+pub struct Score(HashMap<Handle<StandardMaterial>, u32>);
+
+// 记分板 UI 文本
+#[derive(Component)]
+pub struct ScoreText;
+
+// 投射物与坦克都视为球体，半径之和作为命中判定距离
+const CANNON_BALL_RADIUS: f32 = 0.1;
+const TANK_HIT_RADIUS: f32 = 0.8;
+
+// 检测炮弹/导弹与坦克的碰撞，命中后销毁双方并为击中方记分
+#[allow(clippy::type_complexity)] // Query 元组是命中检测所需字段的直接表达，拆分反而更难读
+fn hit_detection(
+    mut commands: Commands,
+    projectiles: Query<
+        (Entity, &Transform, &Handle<StandardMaterial>),
+        Or<(With<CannonBall>, With<Missile>)>,
+    >,
+    tanks: Query<(Entity, &GlobalTransform), With<Tank>>,
+    mut score: ResMut<Score>,
+    mut audio: AudioSink,
+) {
+    let hit_distance_squared = (CANNON_BALL_RADIUS + TANK_HIT_RADIUS).powi(2);
+
+    // 同一帧内可能有多枚投射物同时命中同一辆坦克（despawn 是延迟执行的 command，
+    // 命中判定不会立刻生效），记录本帧已判定死亡的坦克，避免重复销毁和重复记分
+    let mut killed_tanks = HashSet::new();
+
+    for (projectile_entity, projectile_transform, material) in projectiles.iter() {
+        for (tank_entity, tank_transform) in tanks.iter() {
+            if killed_tanks.contains(&tank_entity) {
+                continue;
+            }
+
+            let distance_squared = projectile_transform
+                .translation
+                .distance_squared(tank_transform.translation());
+            if distance_squared <= hit_distance_squared {
+                commands.entity(projectile_entity).despawn();
+                commands.entity(tank_entity).despawn_recursive();
+                killed_tanks.insert(tank_entity);
+                *score.0.entry(material.clone()).or_insert(0) += 1;
+
+                audio.play_impact();
+                break;
+            }
+        }
+    }
+}
+
+// 按 Score 重建记分板文本，每种颜色一行，文字颜色与坦克颜色一致
+fn score_display(
+    score: Res<Score>,
+    materials: Res<Assets<StandardMaterial>>,
+    mut texts: Query<&mut Text, With<ScoreText>>,
+) {
+    let mut text = match texts.get_single_mut() {
+        Ok(text) => text,
+        Err(_) => return,
+    };
+    text.sections = score
+        .0
+        .iter()
+        .filter_map(|(material, count)| {
+            let color = materials.get(material)?.base_color;
+            Some(TextSection::new(
+                format!("{count}\n"),
+                TextStyle {
+                    font_size: 30.0,
+                    color,
+                    ..default()
+                },
+            ))
+        })
+        .collect();
+}
+
 // 检测安全区域
 fn check_safe_zone(
     turrets: Query<(Entity, &GlobalTransform, Option<&Shooting>), With<Turret>>, // 查询 Turret 的 Entity、全局转换、可选的 Shooting 组件数据
@@ -313,4 +716,83 @@ fn check_safe_zone(
             }
         }
     }
-}
\ No newline at end of file
+}
+// 音效资源：启动时加载一次，复用 Handle 避免重复加载
+// 需要把对应的 .ogg 文件放进 assets/sounds/（参见该目录下的 README），缺失时 Bevy 只会打印加载警告，不影响其余功能
+#[derive(Resource)]
+pub struct AudioAssets {
+    fire: Handle<AudioSource>,   // 发射音效
+    bounce: Handle<AudioSource>, // 炮弹落地反弹音效
+    impact: Handle<AudioSource>, // 命中音效
+}
+
+impl FromWorld for AudioAssets {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.resource::<AssetServer>();
+        Self {
+            fire: asset_server.load("sounds/fire.ogg"),
+            bounce: asset_server.load("sounds/bounce.ogg"),
+            impact: asset_server.load("sounds/impact.ogg"),
+        }
+    }
+}
+
+// 每种音效的最短播放间隔，避免几十发炮弹同时触发导致音量爆表
+const AUDIO_MIN_INTERVAL: f32 = 0.05;
+
+// 各音效上一次播放的时刻（启动后经过的秒数）
+#[derive(Resource, Default)]
+pub struct AudioThrottle {
+    fire: f32,
+    bounce: f32,
+    impact: f32,
+}
+
+impl AudioThrottle {
+    // 距上次播放已超过最短间隔则允许播放并记录当前时刻，否则跳过这次播放
+    fn allow(last_played: &mut f32, now: f32) -> bool {
+        if now - *last_played >= AUDIO_MIN_INTERVAL {
+            *last_played = now;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+// 播放一次音效
+fn play_sound(audio_player: &Audio, source: Handle<AudioSource>) {
+    audio_player.play_with_settings(source, PlaybackSettings::ONCE);
+}
+
+// 打包播放音效所需的资源，避免每个会发声的系统都要单独声明 audio/audio_throttle/audio_player/time 四个参数
+#[derive(SystemParam)]
+struct AudioSink<'w> {
+    assets: Res<'w, AudioAssets>,
+    throttle: ResMut<'w, AudioThrottle>,
+    player: Res<'w, Audio>,
+    time: Res<'w, Time>,
+}
+
+impl<'w> AudioSink<'w> {
+    fn play_fire(&mut self) {
+        let now = self.time.elapsed_seconds();
+        if AudioThrottle::allow(&mut self.throttle.fire, now) {
+            play_sound(&self.player, self.assets.fire.clone());
+        }
+    }
+
+    fn play_bounce(&mut self) {
+        let now = self.time.elapsed_seconds();
+        if AudioThrottle::allow(&mut self.throttle.bounce, now) {
+            play_sound(&self.player, self.assets.bounce.clone());
+        }
+    }
+
+    fn play_impact(&mut self) {
+        let now = self.time.elapsed_seconds();
+        if AudioThrottle::allow(&mut self.throttle.impact, now) {
+            play_sound(&self.player, self.assets.impact.clone());
+        }
+    }
+}